@@ -0,0 +1,26 @@
+use iai_callgrind::{
+    library_benchmark, library_benchmark_group, main, LibraryBenchmarkConfig, Tool, ValgrindTool,
+};
+use sample_rust_app::build_report;
+use std::hint::black_box;
+
+/// Picks the auxiliary Valgrind tool iai-callgrind-runner attaches to this
+/// benchmark from `IAI_VALGRIND_TOOL`, so the action can switch between
+/// `dhat`/`massif` at run time without recompiling the benchmark.
+fn memory_tool_config() -> LibraryBenchmarkConfig {
+    let tool = match std::env::var("IAI_VALGRIND_TOOL").as_deref() {
+        Ok("massif") => ValgrindTool::Massif,
+        _ => ValgrindTool::DHAT,
+    };
+    LibraryBenchmarkConfig::default().tool(Tool::new(tool))
+}
+
+#[library_benchmark(config = memory_tool_config())]
+#[bench::small(2_000)]
+#[bench::medium(20_000)]
+fn bench_build_report(iterations: u64) -> Vec<u64> {
+    build_report(black_box(iterations))
+}
+
+library_benchmark_group!(name = heap_benches; benchmarks = bench_build_report);
+main!(library_benchmark_groups = heap_benches);
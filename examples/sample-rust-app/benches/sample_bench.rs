@@ -1,8 +1,10 @@
-use iai_callgrind::{library_benchmark, library_benchmark_group, main};
+use iai_callgrind::{
+    library_benchmark, library_benchmark_group, main, FlamegraphConfig, LibraryBenchmarkConfig,
+};
 use sample_rust_app::workload;
 use std::hint::black_box;
 
-#[library_benchmark]
+#[library_benchmark(config = LibraryBenchmarkConfig::default().flamegraph(FlamegraphConfig::default()))]
 #[bench::small(2_000)]
 #[bench::medium(20_000)]
 fn bench_workload(iterations: u64) -> u64 {
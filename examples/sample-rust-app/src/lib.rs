@@ -20,3 +20,20 @@ fn workload_impl(iterations: u64) -> u64 {
     }
     acc
 }
+
+/// Builds a `Vec` of the default workload's running totals, one entry per
+/// iteration.
+///
+/// Unlike [`workload`], which only returns the final accumulator, this keeps
+/// every intermediate value around so it has a heap allocation profile worth
+/// inspecting with DHAT or Massif. It always uses the default accumulation;
+/// the `alt-impl` feature does not change it.
+pub fn build_report(iterations: u64) -> Vec<u64> {
+    let mut report = Vec::with_capacity(iterations as usize);
+    let mut acc = 0_u64;
+    for i in 1..=iterations {
+        acc = acc.wrapping_add(i.wrapping_mul(31));
+        report.push(acc);
+    }
+    report
+}